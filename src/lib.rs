@@ -9,6 +9,26 @@ const MOON_LATITUDE_OFFSET: f64 = 2451565.2;
 const MOON_LONGITUDE_PERIOD: f64 = 27.321582241; // Longitude oscillation
 const MOON_LONGITUDE_OFFSET: f64 = 2451555.8;
 
+// Mean synodic month used by Meeus' truephase method (days).
+const SYNMONTH: f64 = 29.53058868;
+
+// Equatorial radius of the Earth, used to express distances in km.
+const EARTH_RADIUS_KM: f64 = 6378.14;
+
+// Elements of the epoch-1980.0 solar/lunar model (new_accurate).
+const EPOCH_1980: f64 = 2444238.5; // 1980 January 0.0
+const SUN_ELONG_EPOCH: f64 = 278.833540; // Sun ecliptic longitude at epoch
+const SUN_ELONG_PERIGEE: f64 = 282.596403; // Sun longitude of perigee
+const SUN_ECCENTRICITY: f64 = 0.016718; // Eccentricity of Earth's orbit
+const SUN_SEMI_MAJOR_KM: f64 = 1.495985e8; // Semi-major axis of Earth's orbit
+const MOON_MEAN_LONG_EPOCH: f64 = 64.975464; // Moon mean longitude at epoch
+const MOON_MEAN_PERIGEE_EPOCH: f64 = 349.383063; // Moon mean longitude of perigee
+const MOON_NODE_EPOCH: f64 = 151.950429; // Moon mean longitude of node at epoch
+const MOON_INCLINATION: f64 = 5.145396; // Inclination of the Moon's orbit
+const MOON_ECCENTRICITY: f64 = 0.054900; // Eccentricity of the Moon's orbit
+const MOON_ANGULAR_SIZE: f64 = 0.5181; // Angular size at semi-major distance
+const MOON_SEMI_MAJOR_KM: f64 = 384401.0; // Semi-major axis of the Moon's orbit
+
 // Names of lunar phases
 const PHASE_NAMES: &[&str] = &[
     "New",
@@ -48,6 +68,9 @@ pub struct MoonPhase {
     pub age: f64,                  // Age in days of current cycle
     pub fraction: f64,             // Fraction of illuminated disk
     pub distance: f64,             // Moon distance in earth radii
+    pub distance_km: f64,          // Moon geocentric distance in km
+    pub angular_diameter: f64,     // Moon angular diameter in degrees
+    pub sun_distance_km: f64,      // Sun distance in km
     pub latitude: f64,             // Moon ecliptic latitude
     pub longitude: f64,            // Moon ecliptic longitude
     pub phase_name: &'static str,  // New, Full, etc.
@@ -62,10 +85,54 @@ fn julian_date(time: SystemTime) -> f64 {
     secs / 86400. + 2440587.5
 }
 
+// Julian date of a civil UTC calendar date (standard Meeus formula). Dates on
+// or after 1582 October 15 are treated as Gregorian, earlier ones as Julian.
+fn julian_day(year: i32, month: u32, day: u32, day_fraction: f64) -> f64 {
+    // January and February are counted as months 13 and 14 of the prior year.
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 12)
+    } else {
+        (year, month)
+    };
+
+    let b = if (year, month, day) >= (1582, 10, 15) {
+        let a = (y as f64 / 100.).floor();
+        2. - a + (a / 4.).floor()
+    } else {
+        0.
+    };
+
+    (365.25 * (y as f64 + 4716.)).floor()
+        + (30.6001 * (m as f64 + 1.)).floor()
+        + day_fraction
+        + b
+        - 1524.5
+}
+
 impl MoonPhase {
+    // Thin wrapper over from_julian_date for callers holding a SystemTime.
     pub fn new(time: SystemTime) -> Self {
-        let j_date = julian_date(time);
+        Self::from_julian_date(julian_date(time))
+    }
+
+    // Phase for a civil UTC date, with the Gregorian/Julian calendar
+    // transition handled at 1582 October 15.
+    pub fn from_datetime(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: f64,
+    ) -> Self {
+        let day_fraction = day as f64
+            + (hour as f64 + minute as f64 / 60. + second / 3600.) / 24.;
+        Self::from_julian_date(julian_day(year, month, day, day_fraction))
+    }
 
+    // Simple sinusoid model for an arbitrary Julian date. Accepting a bare
+    // Julian date lets callers query epochs outside SystemTime's range.
+    pub fn from_julian_date(j_date: f64) -> Self {
         // Calculate illumination (synodic) phase.
         // From number of days since new moon on Julian date MOON_SYNODIC_OFFSET
         // (1815UTC January 6, 2000), determine remainder of incomplete cycle.
@@ -100,23 +167,109 @@ impl MoonPhase {
             + 0.7 * (phase_tau).sin())
             % 360.;
 
-        let zodiac_name = ZODIAC_ANGLES
-            .iter()
-            .zip(ZODIAC_NAMES.iter())
-            .find_map(|(angle, name)| {
-                if longitude < *angle {
-                    Some(*name)
-                } else {
-                    None
-                }
-            })
-            .unwrap_or_else(|| ZODIAC_NAMES[0]);
+        let zodiac_name = zodiac_name(longitude);
+
+        // Express the earth-radii distance in km and derive the angular
+        // diameter from it; the simple model carries no solar orbit, so the
+        // Sun distance falls back to its mean value.
+        let distance_km = distance * EARTH_RADIUS_KM;
+        let angular_diameter =
+            MOON_ANGULAR_SIZE * MOON_SEMI_MAJOR_KM / distance_km;
+        let sun_distance_km = SUN_SEMI_MAJOR_KM;
+
+        MoonPhase {
+            j_date,
+            phase,
+            age,
+            fraction,
+            distance,
+            distance_km,
+            angular_diameter,
+            sun_distance_km,
+            latitude,
+            longitude,
+            phase_name,
+            zodiac_name,
+        }
+    }
+
+    // Accurate phase from the epoch-1980.0 solar/lunar model. Unlike `new`,
+    // this gives a meaningful illuminated fraction, geocentric distance in km,
+    // and angular diameter by propagating orbital elements for the instant.
+    pub fn new_accurate(time: SystemTime) -> Self {
+        Self::from_julian_date_accurate(julian_date(time))
+    }
+
+    // The accurate model for an arbitrary Julian date (see new_accurate).
+    pub fn from_julian_date_accurate(j_date: f64) -> Self {
+        let day = j_date - EPOCH_1980;
+
+        // Sun: mean anomaly, eccentric anomaly via Kepler, true anomaly, and
+        // the resulting ecliptic longitude and distance.
+        let n = fixangle((360. / 365.2422) * day);
+        let m = fixangle(n + SUN_ELONG_EPOCH - SUN_ELONG_PERIGEE);
+        let ec = kepler(m, SUN_ECCENTRICITY);
+        let true_anomaly = 2.
+            * (((1. + SUN_ECCENTRICITY) / (1. - SUN_ECCENTRICITY)).sqrt()
+                * (ec / 2.).tan())
+            .atan()
+            .to_degrees();
+        let lambda_sun = fixangle(true_anomaly + SUN_ELONG_PERIGEE);
+        let sun_dist_factor = (1. + SUN_ECCENTRICITY * dcos(true_anomaly))
+            / (1. - SUN_ECCENTRICITY * SUN_ECCENTRICITY);
+        let sun_distance_km = SUN_SEMI_MAJOR_KM / sun_dist_factor;
+
+        // Moon: mean longitude, anomaly, and node; then the periodic
+        // corrections yielding the true ecliptic longitude.
+        let ml = fixangle(13.1763966 * day + MOON_MEAN_LONG_EPOCH);
+        let mm = fixangle(ml - 0.1114041 * day - MOON_MEAN_PERIGEE_EPOCH);
+        let mn = fixangle(MOON_NODE_EPOCH - 0.0529539 * day);
+        let evection = 1.2739 * dsin(2. * (ml - lambda_sun) - mm);
+        let annual_eq = 0.1858 * dsin(m);
+        let a3 = 0.37 * dsin(m);
+        let mm_corrected = mm + evection - annual_eq - a3;
+        let centre_eq = 6.2886 * dsin(mm_corrected);
+        let a4 = 0.214 * dsin(2. * mm_corrected);
+        let lp = ml + evection + centre_eq - annual_eq + a4;
+        let variation = 0.6583 * dsin(2. * (lp - lambda_sun));
+        let true_long = lp + variation;
+
+        // Node-corrected ecliptic longitude and latitude.
+        let long_node = true_long - mn;
+        let longitude = fixangle(
+            (dsin(long_node) * dcos(MOON_INCLINATION))
+                .atan2(dcos(long_node))
+                .to_degrees()
+                + mn,
+        );
+        let latitude =
+            (dsin(long_node) * dsin(MOON_INCLINATION)).asin().to_degrees();
+
+        // Phase angle (elongation from the Sun) and derived quantities.
+        let moon_age = fixangle(true_long - lambda_sun);
+        let phase = moon_age / 360.;
+        let age = phase * SYNMONTH;
+        let fraction = (1. - dcos(moon_age)) / 2.;
+        let phase_name = PHASE_NAMES[(phase * 8.).round() as usize % 8];
+
+        // Geocentric distance, angular diameter, and earth-radii distance.
+        let moon_dist_factor = (1. - MOON_ECCENTRICITY * MOON_ECCENTRICITY)
+            / (1. + MOON_ECCENTRICITY * dcos(mm_corrected + centre_eq));
+        let distance_km = MOON_SEMI_MAJOR_KM * moon_dist_factor;
+        let angular_diameter = MOON_ANGULAR_SIZE / moon_dist_factor;
+        let distance = distance_km / EARTH_RADIUS_KM;
+
+        let zodiac_name = zodiac_name(longitude);
+
         MoonPhase {
             j_date,
             phase,
             age,
             fraction,
             distance,
+            distance_km,
+            angular_diameter,
+            sun_distance_km,
             latitude,
             longitude,
             phase_name,
@@ -124,3 +277,393 @@ impl MoonPhase {
         }
     }
 }
+
+// Sine and cosine taking arguments in degrees, as used throughout Meeus'
+// periodic-term expansions.
+fn dsin(deg: f64) -> f64 {
+    deg.to_radians().sin()
+}
+fn dcos(deg: f64) -> f64 {
+    deg.to_radians().cos()
+}
+
+// Reduce an angle in degrees to the range [0, 360).
+fn fixangle(deg: f64) -> f64 {
+    deg.rem_euclid(360.)
+}
+
+// Solve Kepler's equation E - e*sin E = M (M in degrees) by Newton
+// iteration, returning the eccentric anomaly E in radians.
+fn kepler(m: f64, eccentricity: f64) -> f64 {
+    let m = m.to_radians();
+    let mut e = m;
+    loop {
+        let delta = e - eccentricity * e.sin() - m;
+        e -= delta / (1. - eccentricity * e.cos());
+        if delta.abs() <= 1e-6 {
+            break;
+        }
+    }
+    e
+}
+
+// Resolve the zodiac constellation for an ecliptic longitude.
+fn zodiac_name(longitude: f64) -> &'static str {
+    ZODIAC_ANGLES
+        .iter()
+        .zip(ZODIAC_NAMES.iter())
+        .find_map(|(angle, name)| {
+            if longitude < *angle {
+                Some(*name)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(ZODIAC_NAMES[0])
+}
+
+// Invert julian_date: turn a Julian date back into a SystemTime.
+fn system_time_from_julian(jd: f64) -> SystemTime {
+    let secs = (jd - 2440587.5) * 86400.;
+    if secs >= 0. {
+        SystemTime::UNIX_EPOCH + std::time::Duration::from_secs_f64(secs)
+    } else {
+        SystemTime::UNIX_EPOCH - std::time::Duration::from_secs_f64(-secs)
+    }
+}
+
+// Decimal calendar year of a Julian date, used only to seed the lunation
+// number k. The value is refined by iteration so a coarse estimate is fine.
+fn julian_to_year(jd: f64) -> f64 {
+    2000. + (jd - 2451545.) / 365.25
+}
+
+// One of the four principal phases of the moon.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PrincipalPhase {
+    New,
+    FirstQuarter,
+    Full,
+    LastQuarter,
+}
+
+// A principal phase together with the UTC instant it occurs.
+#[derive(Debug, Copy, Clone)]
+pub struct PhaseMoment {
+    pub phase: PrincipalPhase,
+    pub time: SystemTime,
+}
+
+// The principal phases bracketing a given time, as returned by phase_hunt.
+#[derive(Debug, Copy, Clone)]
+pub struct PhaseHunt {
+    pub new_moon: SystemTime,       // Previous new moon
+    pub first_quarter: SystemTime,  // Following first quarter
+    pub full_moon: SystemTime,      // Following full moon
+    pub last_quarter: SystemTime,   // Following last quarter
+    pub next_new_moon: SystemTime,  // Next new moon
+}
+
+// Meeus' truephase: the Julian Ephemeris Date of the phase `phase`
+// (0.0 new, 0.25 first quarter, 0.5 full, 0.75 last quarter) of lunation k.
+fn truephase(mut k: f64, phase: f64) -> f64 {
+    k += phase;
+    let t = k / 1236.85; // Julian centuries from the 1900 epoch.
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let mut jde = 2415020.75933 + SYNMONTH * k + 0.0001178 * t2
+        - 0.000000155 * t3
+        + 0.00033 * dsin(166.56 + 132.87 * t - 0.009173 * t2);
+
+    // Sun mean anomaly, Moon mean anomaly, and Moon argument of latitude.
+    let m = 359.2242 + 29.10535608 * k - 0.0000333 * t2 - 0.00000347 * t3;
+    let mprime =
+        306.0253 + 385.81691806 * k + 0.0107306 * t2 + 0.00001236 * t3;
+    let f = 21.2964 + 390.67050646 * k - 0.0016528 * t2 - 0.00000239 * t3;
+
+    if phase < 0.01 || (phase - 0.5).abs() < 0.01 {
+        // New or full moon corrections.
+        jde += (0.1734 - 0.000393 * t) * dsin(m) + 0.0021 * dsin(2. * m)
+            - 0.4068 * dsin(mprime)
+            + 0.0161 * dsin(2. * mprime)
+            - 0.0004 * dsin(3. * mprime)
+            + 0.0104 * dsin(2. * f)
+            - 0.0051 * dsin(m + mprime)
+            - 0.0074 * dsin(m - mprime)
+            + 0.0004 * dsin(2. * f + m)
+            - 0.0004 * dsin(2. * f - m)
+            - 0.0006 * dsin(2. * f + mprime)
+            + 0.0010 * dsin(2. * f - mprime)
+            + 0.0005 * dsin(m + 2. * mprime);
+    } else {
+        // First- or last-quarter corrections.
+        jde += (0.1721 - 0.0004 * t) * dsin(m) + 0.0021 * dsin(2. * m)
+            - 0.6280 * dsin(mprime)
+            + 0.0089 * dsin(2. * mprime)
+            - 0.0004 * dsin(3. * mprime)
+            + 0.0079 * dsin(2. * f)
+            - 0.0119 * dsin(m + mprime)
+            - 0.0047 * dsin(m - mprime)
+            + 0.0003 * dsin(2. * f + m)
+            - 0.0004 * dsin(2. * f - m)
+            - 0.0006 * dsin(2. * f + mprime)
+            + 0.0021 * dsin(2. * f - mprime)
+            + 0.0003 * dsin(m + 2. * mprime)
+            + 0.0004 * dsin(m - 2. * mprime)
+            - 0.0003 * dsin(2. * m + mprime);
+        // W-correction, added for first quarter and subtracted for last.
+        let w = 0.0028 - 0.0004 * dcos(m) + 0.0003 * dcos(mprime);
+        if phase < 0.5 {
+            jde += w;
+        } else {
+            jde -= w;
+        }
+    }
+
+    jde
+}
+
+impl MoonPhase {
+    // Exact UTC instants of the principal phases bracketing `time`, using
+    // Meeus' truephase method rather than the simple fract() model.
+    pub fn phase_hunt(time: SystemTime) -> PhaseHunt {
+        let sdate = julian_date(time);
+
+        // Seed the lunation number from a point well before the target so the
+        // search always starts on or before the bracketing new moon.
+        let adate = sdate - 45.;
+        let mut k1 = ((julian_to_year(adate) - 1900.) * 12.3685).floor();
+
+        let mut nt1 = truephase(k1, 0.0);
+        let mut k2;
+        let mut nt2;
+        loop {
+            k2 = k1 + 1.;
+            nt2 = truephase(k2, 0.0);
+            if nt1 <= sdate && nt2 > sdate {
+                break;
+            }
+            k1 = k2;
+            nt1 = nt2;
+        }
+
+        PhaseHunt {
+            new_moon: system_time_from_julian(truephase(k1, 0.0)),
+            first_quarter: system_time_from_julian(truephase(k1, 0.25)),
+            full_moon: system_time_from_julian(truephase(k1, 0.5)),
+            last_quarter: system_time_from_julian(truephase(k1, 0.75)),
+            next_new_moon: system_time_from_julian(truephase(k2, 0.0)),
+        }
+    }
+
+    // Every principal phase occurring in [start, end), in chronological order.
+    pub fn phase_list(start: SystemTime, end: SystemTime) -> Vec<PhaseMoment> {
+        let sdate = julian_date(start);
+        let edate = julian_date(end);
+
+        // Start a couple of lunations early so the first in-range phase is not
+        // missed, then walk forward quarter phase by quarter phase.
+        let mut k = ((julian_to_year(sdate) - 1900.) * 12.3685).floor() - 2.;
+        let kinds = [
+            PrincipalPhase::New,
+            PrincipalPhase::FirstQuarter,
+            PrincipalPhase::Full,
+            PrincipalPhase::LastQuarter,
+        ];
+
+        let mut phases = Vec::new();
+        loop {
+            for (i, kind) in kinds.iter().enumerate() {
+                let d = truephase(k, i as f64 * 0.25);
+                if d >= edate {
+                    return phases;
+                }
+                if d >= sdate {
+                    phases.push(PhaseMoment {
+                        phase: *kind,
+                        time: system_time_from_julian(d),
+                    });
+                }
+            }
+            k += 1.;
+        }
+    }
+}
+
+// Greenwich mean sidereal time (degrees) for a Julian date.
+fn gmst(jd: f64) -> f64 {
+    let d = jd - 2451545.0;
+    fixangle(280.46061837 + 360.98564736629 * d)
+}
+
+// An observer's location on the Earth's surface.
+#[derive(Debug, Copy, Clone)]
+pub struct Observer {
+    pub latitude: f64,  // Degrees north
+    pub longitude: f64, // Degrees east
+    pub elevation: f64, // Metres above sea level
+}
+
+// The moon's position in an observer's horizontal coordinate system.
+#[derive(Debug, Copy, Clone)]
+pub struct MoonPosition {
+    pub altitude: f64, // Degrees above the horizon
+    pub azimuth: f64,  // Degrees east of north
+}
+
+impl MoonPhase {
+    // Right ascension and declination (degrees) from the geocentric ecliptic
+    // longitude/latitude, using the obliquity of the ecliptic.
+    fn equatorial(&self) -> (f64, f64) {
+        let obliquity = 23.4393 - 0.0000004 * (self.j_date - 2451545.0);
+        let ra = (dsin(self.longitude) * dcos(obliquity)
+            - self.latitude.to_radians().tan() * dsin(obliquity))
+        .atan2(dcos(self.longitude))
+        .to_degrees();
+        let dec = (dsin(self.latitude) * dcos(obliquity)
+            + dcos(self.latitude) * dsin(obliquity) * dsin(self.longitude))
+        .asin()
+        .to_degrees();
+        (fixangle(ra), dec)
+    }
+
+    // The moon's altitude and azimuth as seen by `observer` at this instant.
+    pub fn topocentric(&self, observer: Observer) -> MoonPosition {
+        let (ra, dec) = self.equatorial();
+        let lst = gmst(self.j_date) + observer.longitude;
+        let hour_angle = lst - ra;
+
+        let altitude = (dsin(observer.latitude) * dsin(dec)
+            + dcos(observer.latitude) * dcos(dec) * dcos(hour_angle))
+        .asin()
+        .to_degrees();
+        let azimuth = (-dcos(dec) * dsin(hour_angle))
+            .atan2(
+                dsin(dec) * dcos(observer.latitude)
+                    - dcos(dec) * dcos(hour_angle) * dsin(observer.latitude),
+            )
+            .to_degrees();
+
+        MoonPosition {
+            altitude,
+            azimuth: fixangle(azimuth),
+        }
+    }
+
+    // Julian date of the rise or set nearest this instant, or None when the
+    // moon does not cross the horizon on that day (circumpolar cases).
+    // `setting` selects the descending crossing rather than the ascending one.
+    fn crossing_jd(&self, observer: Observer, setting: bool) -> Option<f64> {
+        let (ra, dec) = self.equatorial();
+
+        // Standard lunar horizon: refraction and parallax plus the dip due to
+        // the observer's elevation above sea level.
+        let h0 = -0.8 - 0.0353 * observer.elevation.max(0.).sqrt();
+        let cos_ha = (dsin(h0)
+            - dsin(observer.latitude) * dsin(dec))
+            / (dcos(observer.latitude) * dcos(dec));
+        if !(-1.0..=1.0).contains(&cos_ha) {
+            return None;
+        }
+        let ha = cos_ha.acos().to_degrees();
+
+        // Hour angle of the wanted crossing, then the sidereal time there and
+        // the Julian date it corresponds to, shifted onto the same day.
+        let target_ha = if setting { ha } else { -ha };
+        let lst = ra + target_ha;
+        // The sidereal time repeats every sidereal day, not every solar day.
+        let sidereal_day = 360. / 360.98564736629;
+        let mut jd = 2451545.0
+            + (lst - observer.longitude - 280.46061837) / 360.98564736629;
+        while jd < self.j_date - 0.5 {
+            jd += sidereal_day;
+        }
+        while jd > self.j_date + 0.5 {
+            jd -= sidereal_day;
+        }
+        Some(jd)
+    }
+
+    // UTC instant the moon rises for `observer` on the surrounding day.
+    pub fn rise_time(&self, observer: Observer) -> Option<SystemTime> {
+        self.crossing_jd(observer, false)
+            .map(system_time_from_julian)
+    }
+
+    // UTC instant the moon sets for `observer` on the surrounding day.
+    pub fn set_time(&self, observer: Observer) -> Option<SystemTime> {
+        self.crossing_jd(observer, true).map(system_time_from_julian)
+    }
+}
+
+// Default glyphs used by ascii_art for lit and dark pixels.
+const ASCII_LIT: char = '#';
+const ASCII_DARK: char = ' ';
+
+impl MoonPhase {
+    // Whether the disk point at normalized offset (nx, ny) in [-1, 1] is lit,
+    // or None when the point falls outside the disk. `flip` mirrors the disk
+    // left-to-right for Southern-Hemisphere viewing.
+    fn lit_at(&self, nx: f64, ny: f64, flip: bool) -> Option<bool> {
+        if nx * nx + ny * ny > 1.0 {
+            return None;
+        }
+        let nx = if flip { -nx } else { nx };
+        // Terminator ellipse: horizontal semi-axis |cos(2π·phase)| of the
+        // radius. Waxing phases are lit on the right, waning on the left.
+        let terminator = (TAU * self.phase).cos() * (1.0 - ny * ny).sqrt();
+        let lit = if self.phase < 0.5 {
+            nx >= terminator
+        } else {
+            nx <= -terminator
+        };
+        Some(lit)
+    }
+
+    // Row-major mask of the lit portion of the disk for bitmap/framebuffer
+    // use; pixels outside the disk are false.
+    pub fn fraction_mask(&self, width: usize, height: usize) -> Vec<bool> {
+        let mut mask = Vec::with_capacity(width * height);
+        for row in 0..height {
+            let ny = (row as f64 + 0.5) / height as f64 * 2.0 - 1.0;
+            for col in 0..width {
+                let nx = (col as f64 + 0.5) / width as f64 * 2.0 - 1.0;
+                mask.push(self.lit_at(nx, ny, false).unwrap_or(false));
+            }
+        }
+        mask
+    }
+
+    // Render the illuminated disk as ASCII art using the default glyphs.
+    pub fn ascii_art(&self, width: usize, height: usize) -> String {
+        self.ascii_art_with(width, height, ASCII_LIT, ASCII_DARK, false)
+    }
+
+    // Render the illuminated disk with caller-chosen glyphs for lit and dark
+    // pixels; `flip` mirrors the disk for Southern-Hemisphere viewing. Points
+    // outside the disk are rendered as spaces.
+    pub fn ascii_art_with(
+        &self,
+        width: usize,
+        height: usize,
+        lit: char,
+        dark: char,
+        flip: bool,
+    ) -> String {
+        let mut art = String::with_capacity((width + 1) * height);
+        for row in 0..height {
+            let ny = (row as f64 + 0.5) / height as f64 * 2.0 - 1.0;
+            for col in 0..width {
+                let nx = (col as f64 + 0.5) / width as f64 * 2.0 - 1.0;
+                art.push(match self.lit_at(nx, ny, flip) {
+                    Some(true) => lit,
+                    Some(false) => dark,
+                    None => ' ',
+                });
+            }
+            art.push('\n');
+        }
+        art
+    }
+}